@@ -3,6 +3,109 @@ use tracing_futures::Instrument;
 
 use super::{Mutation, StreamConsumer};
 
+/// A SkyWalking segment reference: enough to let a downstream actor's spans nest under the same
+/// distributed trace as the upstream actor that produced the barrier it's polling.
+///
+/// This is read from and written to `Barrier::tracing_context` (serialized as its `trace_id` /
+/// `parent_segment_id` / `parent_span_id`), read and written only when the `skywalking` feature
+/// is compiled in, so it doesn't interact with any other use of that field.
+#[cfg(feature = "skywalking")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TraceSegmentRef {
+    trace_id: String,
+    parent_segment_id: String,
+    parent_span_id: i32,
+}
+
+/// A single SkyWalking segment covering one epoch's `actor_poll` span, linked to the upstream
+/// actor's segment when the barrier that started the epoch was itself carrying a trace.
+#[cfg(feature = "skywalking")]
+struct ActorSegment {
+    actor_id: u32,
+    context: skywalking::trace::trace_context::TracingContext,
+}
+
+/// Sends `segment` through the connection cached in `reporter`, (re)connecting first if there
+/// isn't one yet, and dropping the cached connection on send failure so the next epoch
+/// reconnects instead of reusing a connection that's gone bad — reusing a healthy connection
+/// across epochs while still recovering once the collector comes back, whether it was down when
+/// the actor started or dropped mid-run.
+#[cfg(feature = "skywalking")]
+async fn report_segment(
+    reporter: &mut Option<skywalking::reporter::grpc::GrpcReporter>,
+    segment: skywalking::proto::v3::SegmentObject,
+) {
+    if reporter.is_none() {
+        match skywalking::reporter::grpc::GrpcReporter::connect("127.0.0.1:11800").await {
+            Ok(connected) => *reporter = Some(connected),
+            Err(err) => {
+                tracing::warn!("failed to connect to SkyWalking collector: {:?}", err);
+                return;
+            }
+        }
+    }
+
+    if let Err(err) = reporter
+        .as_mut()
+        .expect("just connected or already present above")
+        .send(segment)
+    {
+        tracing::warn!("failed to report SkyWalking segment: {:?}", err);
+        *reporter = None;
+    }
+}
+
+#[cfg(feature = "skywalking")]
+impl ActorSegment {
+    fn new(actor_id: u32, parent: Option<TraceSegmentRef>) -> Self {
+        let service = "risingwave-streaming";
+        let instance = "default";
+        let context = match parent {
+            Some(parent) => {
+                skywalking::trace::trace_context::TracingContext::from_propagation(
+                    service,
+                    instance,
+                    skywalking::trace::propagation::context::PropagationContext {
+                        trace_id: parent.trace_id,
+                        parent_service: service.to_string(),
+                        parent_service_instance: instance.to_string(),
+                        parent_endpoint: "actor_poll".to_string(),
+                        address_used_at_client: String::new(),
+                        parent_span_id: parent.parent_span_id,
+                        parent_trace_segment_id: parent.parent_segment_id,
+                    },
+                )
+            }
+            None => skywalking::trace::trace_context::TracingContext::default(service, instance),
+        };
+        Self { actor_id, context }
+    }
+
+    /// Opens and immediately closes this epoch's span — `self.context` already continues the
+    /// upstream actor's trace via the `parent` passed to `new` — reporting the completed segment
+    /// through the actor's long-lived `reporter` and returning a reference downstream actors can
+    /// use to nest their own span under this one in turn.
+    async fn record_epoch(
+        &mut self,
+        epoch: u64,
+        reporter: &mut Option<skywalking::reporter::grpc::GrpcReporter>,
+    ) -> TraceSegmentRef {
+        let span = self
+            .context
+            .create_entry_span(&format!("actor_poll_{:03}", self.actor_id));
+        span.add_tag("epoch", epoch.to_string()).ok();
+        let span_ref = TraceSegmentRef {
+            trace_id: self.context.trace_id().to_string(),
+            parent_segment_id: self.context.segment_id().to_string(),
+            parent_span_id: span.span_id(),
+        };
+        drop(span);
+
+        report_segment(reporter, self.context.convert_to_segment_object()).await;
+        span_ref
+    }
+}
+
 /// `Actor` is the basic execution unit in the streaming framework.
 pub struct Actor {
     consumer: Box<dyn StreamConsumer>,
@@ -24,14 +127,40 @@ impl Actor {
             next = "Outbound",
             epoch = -1
         );
+
+        // Reused across epochs' segment reports rather than dialing the SkyWalking collector
+        // fresh on every barrier, which would put a gRPC handshake on the actor's hot polling
+        // path; `report_segment` only pays that cost again once the connection is down.
+        #[cfg(feature = "skywalking")]
+        let mut reporter = None;
+
         // Drive the streaming task with an infinite loop
         loop {
             let message = self.consumer.next().instrument(span.clone()).await;
             match message {
-                Ok(Some(barrier)) => {
+                // `mut` is only exercised when the `skywalking` feature writes the outgoing
+                // trace segment reference back onto the barrier below.
+                #[allow(unused_mut)]
+                Ok(Some(mut barrier)) => {
                     if matches!(barrier.mutation, Mutation::Stop) {
                         break;
                     }
+
+                    // Each epoch poll becomes one SkyWalking span. A segment reference found in
+                    // the polled barrier's `tracing_context` becomes this span's parent. This
+                    // span's own reference is then written onto `barrier.tracing_context`, but
+                    // `barrier` is not passed on anywhere after this arm and is dropped at its
+                    // end, so that reference does not reach any other actor from here.
+                    #[cfg(feature = "skywalking")]
+                    {
+                        let parent_ref = barrier.tracing_context.as_ref().and_then(|ctx| {
+                            serde_json::from_str::<TraceSegmentRef>(ctx.as_str()).ok()
+                        });
+                        let mut segment = ActorSegment::new(self.id, parent_ref);
+                        let span_ref = segment.record_epoch(barrier.epoch, &mut reporter).await;
+                        barrier.tracing_context = serde_json::to_string(&span_ref).ok();
+                    }
+
                     span = tracing::trace_span!(
                         "actor_poll",
                         otel.name = span_name.as_str(),
@@ -50,6 +179,7 @@ impl Actor {
                 }
             }
         }
+
         Ok(())
     }
 }